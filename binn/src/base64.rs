@@ -0,0 +1,72 @@
+//! A small, dependency-free standard-alphabet base64 codec.
+//!
+//! Only [`crate::BinnObject::to_base64`]/[`crate::BinnObject::from_base64`]
+//! and the `Blob` handling in [`crate::BinnObject::to_json`]/
+//! [`crate::BinnObject::from_json`] use this; it isn't meant to be a
+//! general-purpose base64 implementation.
+
+const ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = *chunk.get(1).unwrap_or(&0);
+        let b2 = *chunk.get(2).unwrap_or(&0);
+
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[((b0 << 4 | b1 >> 4) & 0x3f) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[((b1 << 2 | b2 >> 6) & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+#[derive(Debug)]
+pub(crate) struct DecodeError;
+
+fn decode_char(c: u8) -> Result<u8, DecodeError> {
+    match c {
+        b'A'..=b'Z' => Ok(c - b'A'),
+        b'a'..=b'z' => Ok(c - b'a' + 26),
+        b'0'..=b'9' => Ok(c - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(DecodeError),
+    }
+}
+
+/// Decodes a standard-alphabet base64 string, accepting input with or
+/// without trailing `=` padding.
+pub(crate) fn decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    let input: Vec<u8> = s.bytes().filter(|&b| b != b'=').collect();
+    if input.len() % 4 == 1 {
+        return Err(DecodeError);
+    }
+
+    let mut out = Vec::with_capacity(input.len() / 4 * 3 + 3);
+    for group in input.chunks(4) {
+        let mut sextets = [0u8; 4];
+        for (i, &c) in group.iter().enumerate() {
+            sextets[i] = decode_char(c)?;
+        }
+
+        out.push(sextets[0] << 2 | sextets[1] >> 4);
+        if group.len() > 2 {
+            out.push(sextets[1] << 4 | sextets[2] >> 2);
+        }
+        if group.len() > 3 {
+            out.push(sextets[2] << 6 | sextets[3]);
+        }
+    }
+    Ok(out)
+}
@@ -0,0 +1,454 @@
+//! A `serde::Deserializer` that reads directly out of a binn buffer.
+
+use std::ffi::CString;
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+
+use crate::error::{Error, Result};
+use crate::{BinnList, BinnObject, BinnValue};
+
+/// Deserializes `T` out of a binn buffer produced by [`crate::to_bytes`].
+///
+/// The root can be an object or a list — `to_bytes` picks whichever one
+/// `T` serializes to — so this opens it as whatever it actually is instead
+/// of assuming an object (see [`crate::raw::open_root`]). A buffer whose
+/// root is a binn map opens, but deserializing it returns an error.
+pub fn from_bytes<T: DeserializeOwned>(data: &[u8]) -> Result<T> {
+    let value = crate::raw::open_root(data)?;
+    T::deserialize(Deserializer { value })
+}
+
+pub struct Deserializer<'de> {
+    value: BinnValue<'de>,
+}
+
+macro_rules! deserialize_scalar {
+    ($method:ident) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+            self.deserialize_any(visitor)
+        }
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.value {
+            BinnValue::Int8(x) => visitor.visit_i8(x),
+            BinnValue::Int16(x) => visitor.visit_i16(x),
+            BinnValue::Int32(x) => visitor.visit_i32(x),
+            BinnValue::Int64(x) => visitor.visit_i64(x),
+            BinnValue::UInt8(x) => visitor.visit_u8(x),
+            BinnValue::UInt16(x) => visitor.visit_u16(x),
+            BinnValue::UInt32(x) => visitor.visit_u32(x),
+            BinnValue::UInt64(x) => visitor.visit_u64(x),
+            BinnValue::Float32(x) => visitor.visit_f32(x),
+            BinnValue::Float64(x) => visitor.visit_f64(x),
+            BinnValue::Bool(x) => visitor.visit_bool(x),
+            BinnValue::Str(s) => {
+                visitor.visit_str(s.to_str().map_err(|e| Error::Message(e.to_string()))?)
+            }
+            BinnValue::Blob(b) => visitor.visit_bytes(b),
+            BinnValue::Object(obj) => visitor.visit_map(ObjectMapAccess::new(obj)),
+            BinnValue::List(list) => visitor.visit_seq(ListSeqAccess { list, pos: 1 }),
+            BinnValue::Map(_) => Err(Error::Message(
+                "binn maps are not supported by the serde bridge yet".into(),
+            )),
+        }
+    }
+
+    deserialize_scalar!(deserialize_bool);
+    deserialize_scalar!(deserialize_i8);
+    deserialize_scalar!(deserialize_i16);
+    deserialize_scalar!(deserialize_i32);
+    deserialize_scalar!(deserialize_i64);
+    deserialize_scalar!(deserialize_u8);
+    deserialize_scalar!(deserialize_u16);
+    deserialize_scalar!(deserialize_u32);
+    deserialize_scalar!(deserialize_u64);
+    deserialize_scalar!(deserialize_f32);
+    deserialize_scalar!(deserialize_f64);
+    deserialize_scalar!(deserialize_char);
+    deserialize_scalar!(deserialize_str);
+    deserialize_scalar!(deserialize_string);
+    deserialize_scalar!(deserialize_seq);
+    deserialize_scalar!(deserialize_map);
+    deserialize_scalar!(deserialize_bytes);
+    deserialize_scalar!(deserialize_byte_buf);
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        // A `Deserializer` is only ever constructed for a key that is
+        // present, so from here the value is always `Some`; a missing
+        // `Option` field is handled by `FieldMapAccess` simply never
+        // producing that key.
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_unit(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.value {
+            BinnValue::Object(obj) => visitor.visit_map(FieldMapAccess {
+                obj,
+                fields,
+                pos: 0,
+                current_key: None,
+            }),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.value {
+            BinnValue::Str(s) => visitor.visit_enum(
+                s.to_str()
+                    .map_err(|e| Error::Message(e.to_string()))?
+                    .into_deserializer(),
+            ),
+            BinnValue::Object(obj) => visitor.visit_enum(VariantDeserializer { obj }),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+}
+
+/// `BinnObject`/`BinnList` carry no lifetime of their own, so `get()` ties
+/// its result to the borrow of `&self` that produced it rather than to the
+/// buffer backing it. That's the right call for `get()` as a general-purpose
+/// accessor, but every container the access structs below hold was reached
+/// by walking down from [`crate::raw::open_root`]'s result, and `BinnObject`/
+/// `BinnList` never copy on a nested read (see their doc comments) — so a
+/// value read out of one is really backed by the same buffer `'de` already
+/// borrows from, just not provably so to the type system.
+///
+/// # Safety
+/// `value` must have been read, directly or transitively, out of a
+/// container opened over the buffer `'de` borrows from.
+unsafe fn reborrow<'de>(value: BinnValue<'_>) -> BinnValue<'de> {
+    std::mem::transmute(value)
+}
+
+/// Drives `MapAccess` over a struct's known field list, looking each one up
+/// by name instead of walking the object's entries. A field that isn't
+/// present is simply skipped, which is how `Option<T>` fields default to
+/// `None` without binn needing its own null tag.
+struct FieldMapAccess {
+    obj: BinnObject,
+    fields: &'static [&'static str],
+    pos: usize,
+    current_key: Option<CString>,
+}
+
+impl<'de> MapAccess<'de> for FieldMapAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        loop {
+            let field = match self.fields.get(self.pos) {
+                Some(&field) => field,
+                None => return Ok(None),
+            };
+            self.pos += 1;
+            let key = CString::new(field).map_err(|e| Error::Message(e.to_string()))?;
+            if self.obj.get(&key).is_none() {
+                continue;
+            }
+            self.current_key = Some(key);
+            return seed.deserialize(field.into_deserializer()).map(Some);
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value> {
+        let key = self.current_key.take().ok_or(Error::Eof)?;
+        let value = self.obj.get(&key).ok_or(Error::Eof)?;
+        // SAFETY: `value` was just read out of `self.obj`, reached from the
+        // root value `from_bytes` opened over the `'de` buffer.
+        seed.deserialize(Deserializer { value: unsafe { reborrow(value) } })
+    }
+}
+
+/// Drives `MapAccess` over every entry in an object, for types like
+/// `HashMap<String, _>` that don't come with a fixed field list.
+struct ObjectMapAccess {
+    obj: BinnObject,
+    keys: std::vec::IntoIter<CString>,
+    current_key: Option<CString>,
+}
+
+impl ObjectMapAccess {
+    fn new(obj: BinnObject) -> Self {
+        let keys = obj.keys().into_iter();
+        Self {
+            obj,
+            keys,
+            current_key: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for ObjectMapAccess {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.keys.next() {
+            Some(key) => {
+                let name = key
+                    .to_str()
+                    .map_err(|e| Error::Message(e.to_string()))?
+                    .to_owned();
+                self.current_key = Some(key);
+                seed.deserialize(name.into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value> {
+        let key = self.current_key.take().ok_or(Error::Eof)?;
+        let value = self.obj.get(&key).ok_or(Error::Eof)?;
+        // SAFETY: see `FieldMapAccess::next_value_seed`.
+        seed.deserialize(Deserializer { value: unsafe { reborrow(value) } })
+    }
+}
+
+struct ListSeqAccess {
+    list: BinnList,
+    pos: i32,
+}
+
+impl<'de> SeqAccess<'de> for ListSeqAccess {
+    type Error = Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>> {
+        match self.list.get(self.pos) {
+            Some(value) => {
+                self.pos += 1;
+                // SAFETY: see `FieldMapAccess::next_value_seed`.
+                seed.deserialize(Deserializer { value: unsafe { reborrow(value) } }).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// Stands in for a newtype variant's elided `()`/`None` payload (see
+/// [`crate::ser::Serializer::serialize_newtype_variant`]): reads as unit
+/// for any shape that can hold one, and is otherwise unreachable, since
+/// this is only ever handed to the variant's own payload type.
+struct ElidedDeserializer;
+
+impl<'de> de::Deserializer<'de> for ElidedDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_none()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// `serde`'s externally-tagged enum convention: a unit variant is the bare
+/// variant name (`BinnValue::Str`), anything else is a single-key object
+/// whose key is the variant name, mirroring [`crate::ser`]'s
+/// `serialize_newtype_variant`/`serialize_tuple_variant`/`serialize_struct_variant`.
+struct VariantDeserializer {
+    obj: BinnObject,
+}
+
+impl VariantDeserializer {
+    fn variant_key(&self) -> Result<CString> {
+        self.obj.keys().into_iter().next().ok_or(Error::WrongType)
+    }
+}
+
+impl<'de> EnumAccess<'de> for VariantDeserializer {
+    type Error = Error;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let key = self.variant_key()?;
+        let name = key
+            .to_str()
+            .map_err(|e| Error::Message(e.to_string()))?
+            .to_owned();
+        let variant = seed.deserialize(<String as IntoDeserializer<'de, Error>>::into_deserializer(name))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> Result<S::Value> {
+        let key = self.variant_key()?;
+        match self.obj.get(&key).ok_or(Error::WrongType)? {
+            // `ser::Serializer::serialize_newtype_variant` marks an
+            // elided `()`/`None` payload with an empty nested object, since
+            // the key itself can't be omitted here the way an ordinary
+            // struct field can. (A genuine zero-field struct payload would
+            // look the same; that's an accepted ambiguity.)
+            BinnValue::Object(o) if o.keys().is_empty() => seed.deserialize(ElidedDeserializer),
+            // SAFETY: see `FieldMapAccess::next_value_seed`.
+            value => seed.deserialize(Deserializer { value: unsafe { reborrow(value) } }),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        let key = self.variant_key()?;
+        let value = self.obj.get(&key).ok_or(Error::WrongType)?;
+        // SAFETY: see `FieldMapAccess::next_value_seed`.
+        let value = unsafe { reborrow(value) };
+        de::Deserializer::deserialize_seq(Deserializer { value }, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        let key = self.variant_key()?;
+        match self.obj.get(&key) {
+            Some(BinnValue::Object(obj)) => visitor.visit_map(FieldMapAccess {
+                obj,
+                fields,
+                pos: 0,
+                current_key: None,
+            }),
+            _ => Err(Error::WrongType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{from_bytes, to_bytes};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+        label: Option<String>,
+    }
+
+    fn round_trip<T: Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+        from_bytes(&to_bytes(value).expect("serialize")).expect("deserialize")
+    }
+
+    #[test]
+    fn struct_round_trip_test() {
+        let with_label = Point { x: 1, y: -2, label: Some("origin".into()) };
+        assert_eq!(round_trip(&with_label), with_label);
+
+        let without_label = Point { x: 0, y: 0, label: None };
+        assert_eq!(round_trip(&without_label), without_label);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Radius(f64),
+        // Also exercises a newtype variant whose payload serializes to
+        // `Value::Unit` (`None`) — the case `serialize_newtype_variant`/
+        // `newtype_variant_seed` previously lost the variant's key for.
+        Labeled(Option<String>),
+        Rect(f64, f64),
+        Named { name: String, sides: u32 },
+    }
+
+    #[test]
+    fn enum_every_variant_kind_round_trip_test() {
+        let values = [
+            Shape::Empty,
+            Shape::Radius(1.5),
+            Shape::Labeled(Some("circle".into())),
+            Shape::Labeled(None),
+            Shape::Rect(2.0, 3.0),
+            Shape::Named { name: "square".into(), sides: 4 },
+        ];
+        for value in values {
+            assert_eq!(round_trip(&value), value);
+        }
+    }
+
+    #[test]
+    fn vec_round_trip_test() {
+        let value = vec![1i32, 2, 3, 4];
+        assert_eq!(round_trip(&value), value);
+    }
+
+    #[test]
+    fn hashmap_round_trip_test() {
+        let mut value = HashMap::new();
+        value.insert("a".to_string(), 1i32);
+        value.insert("b".to_string(), 2i32);
+        assert_eq!(round_trip(&value), value);
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// Errors produced by the `serde` bridge in [`crate::ser`] and [`crate::de`].
+///
+/// Kept separate from [`crate::WrongBinnValue`] and [`crate::BinnOpenError`]
+/// because `serde::ser::Error`/`serde::de::Error` require a type that carries
+/// an arbitrary message and implements `std::error::Error`.
+#[derive(Debug)]
+pub enum Error {
+    /// A message produced by `serde`'s `Serialize`/`Deserialize` impls, or by
+    /// one of our own error paths via `serde::ser::Error::custom`.
+    Message(String),
+    /// The bytes did not contain a valid binn buffer.
+    Open,
+    /// A value was read back with a type tag incompatible with the type
+    /// being deserialized.
+    WrongType,
+    /// The input ended before the expected value was fully read.
+    Eof,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Message(msg) => f.write_str(msg),
+            Error::Open => f.write_str("not a valid binn buffer"),
+            Error::WrongType => f.write_str("value has an unexpected binn type"),
+            Error::Eof => f.write_str("unexpected end of binn data"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Message(msg.to_string())
+    }
+}
+
+impl From<crate::BinnOpenError> for Error {
+    fn from(_: crate::BinnOpenError) -> Self {
+        Error::Open
+    }
+}
+
+impl From<crate::WrongBinnValue> for Error {
+    fn from(_: crate::WrongBinnValue) -> Self {
+        Error::WrongType
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
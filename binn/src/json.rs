@@ -0,0 +1,260 @@
+//! A small, dependency-free JSON encoder/decoder, used only by
+//! [`crate::BinnObject::to_json`]/[`crate::BinnObject::from_json`]. It only
+//! needs to understand the shapes binn itself can produce (objects, arrays,
+//! strings, numbers, bools, null), not arbitrary JSON.
+
+use std::fmt::Write as _;
+
+/// The largest magnitude an integer can have and still round-trip through
+/// an IEEE 754 double (2^53). `Int64`/`UInt64` values past this are quoted
+/// as decimal strings on the way out, and unquoted on the way back in, so
+/// clients that parse JSON numbers as floats don't lose precision.
+pub(crate) const MAX_SAFE_INT: i128 = 9_007_199_254_740_992;
+
+pub(crate) fn push_escaped_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Appends `value` as a JSON number, or (past [`MAX_SAFE_INT`]) as a quoted
+/// decimal string.
+pub(crate) fn push_int(out: &mut String, value: i128) {
+    if value > MAX_SAFE_INT || value < -MAX_SAFE_INT {
+        let _ = write!(out, "\"{value}\"");
+    } else {
+        let _ = write!(out, "{value}");
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseError;
+
+pub(crate) fn parse(input: &str) -> Result<Value, ParseError> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        pos: 0,
+    };
+    let value = parser.parse_value()?;
+    parser.skip_ws();
+    if parser.pos != parser.chars.len() {
+        return Err(ParseError);
+    }
+    Ok(value)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.bump() == Some(c) {
+            Ok(())
+        } else {
+            Err(ParseError)
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, ParseError> {
+        self.skip_ws();
+        match self.peek().ok_or(ParseError)? {
+            '{' => self.parse_object(),
+            '[' => self.parse_array(),
+            '"' => self.parse_string().map(Value::Str),
+            't' => self.parse_lit("true", Value::Bool(true)),
+            'f' => self.parse_lit("false", Value::Bool(false)),
+            'n' => self.parse_lit("null", Value::Null),
+            '-' | '0'..='9' => self.parse_number(),
+            _ => Err(ParseError),
+        }
+    }
+
+    fn parse_lit(&mut self, lit: &str, value: Value) -> Result<Value, ParseError> {
+        if self.chars[self.pos..].starts_with(&lit.chars().collect::<Vec<_>>()[..]) {
+            self.pos += lit.chars().count();
+            Ok(value)
+        } else {
+            Err(ParseError)
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, ParseError> {
+        self.expect('{')?;
+        let mut entries = Vec::new();
+        self.skip_ws();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Value::Object(entries));
+        }
+        loop {
+            self.skip_ws();
+            let key = self.parse_string()?;
+            self.skip_ws();
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+            self.skip_ws();
+            match self.bump().ok_or(ParseError)? {
+                ',' => continue,
+                '}' => break,
+                _ => return Err(ParseError),
+            }
+        }
+        Ok(Value::Object(entries))
+    }
+
+    fn parse_array(&mut self) -> Result<Value, ParseError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_ws();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_ws();
+            match self.bump().ok_or(ParseError)? {
+                ',' => continue,
+                ']' => break,
+                _ => return Err(ParseError),
+            }
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn parse_string(&mut self) -> Result<String, ParseError> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.bump().ok_or(ParseError)? {
+                '"' => break,
+                '\\' => match self.bump().ok_or(ParseError)? {
+                    '"' => out.push('"'),
+                    '\\' => out.push('\\'),
+                    '/' => out.push('/'),
+                    'n' => out.push('\n'),
+                    'r' => out.push('\r'),
+                    't' => out.push('\t'),
+                    'u' => out.push(self.parse_unicode_char()?),
+                    _ => return Err(ParseError),
+                },
+                c => out.push(c),
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<u32, ParseError> {
+        let mut cp = 0u32;
+        for _ in 0..4 {
+            cp = cp * 16 + self.bump().ok_or(ParseError)?.to_digit(16).ok_or(ParseError)?;
+        }
+        Ok(cp)
+    }
+
+    /// Parses the code unit(s) following a `\u` escape already consumed by
+    /// the caller. Non-BMP characters are encoded by JSON as a UTF-16
+    /// surrogate pair (e.g. `json.dumps(..., ensure_ascii=True)` emits emoji
+    /// this way), so a high surrogate must be combined with the low
+    /// surrogate from an immediately following `\uXXXX` escape.
+    fn parse_unicode_char(&mut self) -> Result<char, ParseError> {
+        let high = self.parse_unicode_escape()?;
+        if !(0xD800..=0xDBFF).contains(&high) {
+            return char::from_u32(high).ok_or(ParseError);
+        }
+        if self.bump() != Some('\\') || self.bump() != Some('u') {
+            return Err(ParseError);
+        }
+        let low = self.parse_unicode_escape()?;
+        if !(0xDC00..=0xDFFF).contains(&low) {
+            return Err(ParseError);
+        }
+        let combined = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+        char::from_u32(combined).ok_or(ParseError)
+    }
+
+    fn parse_number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let mut is_float = false;
+        if self.peek() == Some('.') {
+            is_float = true;
+            self.pos += 1;
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+        }
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            is_float = true;
+            self.pos += 1;
+            if matches!(self.peek(), Some('+') | Some('-')) {
+                self.pos += 1;
+            }
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        if is_float {
+            text.parse::<f64>().map(Value::Float).map_err(|_| ParseError)
+        } else {
+            text.parse::<i128>().map(Value::Int).map_err(|_| ParseError)
+        }
+    }
+}
+
+/// Parses a bare decimal integer, accepting the quoted-string form
+/// `to_json` uses for big `Int64`/`UInt64` values.
+pub(crate) fn parse_decimal_str(s: &str) -> Option<i128> {
+    if s.is_empty() || s == "-" {
+        return None;
+    }
+    s.parse::<i128>().ok()
+}
@@ -1,11 +1,29 @@
 use std::{
     convert::{TryFrom, TryInto},
-    ffi::{c_void, CStr},
+    ffi::{c_void, CStr, CString},
+    fmt::Write as _,
     os::raw::{c_char, c_int},
 };
 
 use binn_sys::binn_ptr;
 
+mod base64;
+pub mod de;
+mod error;
+mod json;
+mod list;
+mod map;
+mod owned;
+mod raw;
+pub mod ser;
+
+pub use de::from_bytes;
+pub use error::Error;
+pub use list::BinnList;
+pub use map::BinnMap;
+pub use owned::BinnValueOwned;
+pub use ser::to_bytes;
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub enum BinnValue<'a> {
@@ -21,7 +39,10 @@ pub enum BinnValue<'a> {
     Float64(f64),
     Bool(bool),
     Str(&'a CStr),
+    Blob(&'a [u8]),
     Object(BinnObject),
+    List(BinnList),
+    Map(BinnMap),
 }
 
 macro_rules! impl_from {
@@ -46,7 +67,10 @@ impl_from!(f32, Float32);
 impl_from!(f64, Float64);
 impl_from!(bool, Bool);
 impl_from!(&'a CStr, Str);
+impl_from!(&'a [u8], Blob);
 impl_from!(BinnObject, Object);
+impl_from!(BinnList, List);
+impl_from!(BinnMap, Map);
 
 #[derive(Debug)]
 pub struct WrongBinnValue;
@@ -79,46 +103,126 @@ impl_tryfrom!(f32, Float32);
 impl_tryfrom!(f64, Float64);
 impl_tryfrom!(bool, Bool);
 impl_tryfrom!(&'a CStr, Str);
+impl_tryfrom!(&'a [u8], Blob);
 impl_tryfrom!(BinnObject, Object);
+impl_tryfrom!(BinnList, List);
+impl_tryfrom!(BinnMap, Map);
+
+/// Cross-type numeric coercion for [`BinnObject::get_coerced`].
+///
+/// Unlike `TryFrom<BinnValue>`, which only matches the exact stored variant,
+/// this converts between any numeric kind (and `bool`) the way the caller
+/// asked for, failing rather than truncating when a conversion doesn't fit.
+pub trait CoerceFromBinn: Sized {
+    fn coerce_from_binn(value: BinnValue) -> Option<Self>;
+}
 
+/// Converts a truncated (non-fractional) `f64` into `T`, failing if it's out
+/// of range for `T` rather than wrapping or saturating.
+fn coerce_float_to_int<T: TryFrom<i128>>(x: f64) -> Option<T> {
+    if !x.is_finite() {
+        return None;
+    }
+    let truncated = x.trunc();
+    if truncated < i128::MIN as f64 || truncated > i128::MAX as f64 {
+        return None;
+    }
+    T::try_from(truncated as i128).ok()
+}
+
+macro_rules! impl_coerce_int {
+    ($t:ty) => {
+        impl CoerceFromBinn for $t {
+            fn coerce_from_binn(value: BinnValue) -> Option<Self> {
+                match value {
+                    BinnValue::Int8(x) => <$t>::try_from(x).ok(),
+                    BinnValue::Int16(x) => <$t>::try_from(x).ok(),
+                    BinnValue::Int32(x) => <$t>::try_from(x).ok(),
+                    BinnValue::Int64(x) => <$t>::try_from(x).ok(),
+                    BinnValue::UInt8(x) => <$t>::try_from(x).ok(),
+                    BinnValue::UInt16(x) => <$t>::try_from(x).ok(),
+                    BinnValue::UInt32(x) => <$t>::try_from(x).ok(),
+                    BinnValue::UInt64(x) => <$t>::try_from(x).ok(),
+                    BinnValue::Bool(x) => Some(x as u8 as $t),
+                    BinnValue::Float32(x) => coerce_float_to_int(x as f64),
+                    BinnValue::Float64(x) => coerce_float_to_int(x),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_coerce_int!(i8);
+impl_coerce_int!(i16);
+impl_coerce_int!(i32);
+impl_coerce_int!(i64);
+impl_coerce_int!(u8);
+impl_coerce_int!(u16);
+impl_coerce_int!(u32);
+impl_coerce_int!(u64);
+
+macro_rules! impl_coerce_float {
+    ($t:ty) => {
+        impl CoerceFromBinn for $t {
+            fn coerce_from_binn(value: BinnValue) -> Option<Self> {
+                match value {
+                    BinnValue::Int8(x) => Some(x as $t),
+                    BinnValue::Int16(x) => Some(x as $t),
+                    BinnValue::Int32(x) => Some(x as $t),
+                    BinnValue::Int64(x) => Some(x as $t),
+                    BinnValue::UInt8(x) => Some(x as $t),
+                    BinnValue::UInt16(x) => Some(x as $t),
+                    BinnValue::UInt32(x) => Some(x as $t),
+                    BinnValue::UInt64(x) => Some(x as $t),
+                    BinnValue::Bool(x) => Some(x as u8 as $t),
+                    BinnValue::Float32(x) => Some(x as $t),
+                    BinnValue::Float64(x) => Some(x as $t),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_coerce_float!(f32);
+impl_coerce_float!(f64);
+
+/// `binn_open` doesn't copy the buffer it's given — it just wraps the
+/// pointer in a read-only view, so the opened handle is only valid for as
+/// long as that buffer is. The second field owns that buffer for handles
+/// that need to be independent of it (see `Clone`); handles built directly
+/// through the C API (`new()`) manage their own storage and leave it `None`.
+/// [`BinnList`] and [`BinnMap`] carry the same second field for the same
+/// reason.
 #[derive(Debug)]
-pub struct BinnObject(*mut binn_sys::binn);
+pub struct BinnObject(*mut binn_sys::binn, Option<Vec<u8>>);
 
 impl<'a> BinnObject {
     pub fn new() -> Self {
         unsafe {
             let mut obj = binn_sys::binn_object();
             (*obj).disable_int_compression = true as i32;
-            Self(obj)
+            Self(obj, None)
         }
     }
 
+    /// Opens a handle over an owned copy of `buf`, keeping the copy alive
+    /// alongside the handle so it's independent of wherever `buf` came from.
+    fn from_owned_bytes(buf: Vec<u8>) -> Self {
+        let binn = unsafe { binn_sys::binn_open(buf.as_ptr() as *mut c_void) };
+        Self(binn, Some(buf))
+    }
+
+    /// Wraps an already-`binn_open`ed handle, without copying or reopening
+    /// it. Used by [`raw::open_root`] once it's confirmed the handle's
+    /// container type is actually `BINN_OBJECT`.
+    pub(crate) fn from_open_ptr(ptr: *mut binn_sys::binn) -> Self {
+        Self(ptr, None)
+    }
+
     pub fn set<T: Into<BinnValue<'a>>>(&mut self, key: &CStr, value: T) {
-        fn addr<T>(x: &T) -> *mut c_void {
-            x as *const T as *mut c_void
-        }
-        match value.into() {
-            BinnValue::Int8(x) => self.set_object(key, binn_sys::BINN_INT8, addr(&x), 0),
-            BinnValue::Int16(x) => self.set_object(key, binn_sys::BINN_INT16, addr(&x), 0),
-            BinnValue::Int32(x) => self.set_object(key, binn_sys::BINN_INT32, addr(&x), 0),
-            BinnValue::Int64(x) => self.set_object(key, binn_sys::BINN_INT64, addr(&x), 0),
-            BinnValue::UInt8(x) => self.set_object(key, binn_sys::BINN_UINT8, addr(&x), 0),
-            BinnValue::UInt16(x) => self.set_object(key, binn_sys::BINN_UINT16, addr(&x), 0),
-            BinnValue::UInt32(x) => self.set_object(key, binn_sys::BINN_UINT32, addr(&x), 0),
-            BinnValue::UInt64(x) => self.set_object(key, binn_sys::BINN_UINT64, addr(&x), 0),
-            BinnValue::Float32(x) => self.set_object(key, binn_sys::BINN_FLOAT32, addr(&x), 0),
-            BinnValue::Float64(x) => self.set_object(key, binn_sys::BINN_FLOAT64, addr(&x), 0),
-            BinnValue::Bool(x) => self.set_object(key, binn_sys::BINN_BOOL, addr(&x), 0),
-            BinnValue::Str(x) => {
-                self.set_object(key, binn_sys::BINN_STRING, x.as_ptr() as *mut c_void, 0)
-            }
-            BinnValue::Object(x) => {
-                let bytes = x.as_bytes();
-                let ptr = bytes.as_ptr() as *mut c_void;
-                let size = bytes.len();
-                self.set_object(key, binn_sys::BINN_OBJECT, ptr, size)
-            }
-        };
+        raw::value_to_raw(value.into(), |ty, ptr, size| self.set_object(key, ty, ptr, size));
     }
 
     fn set_object(&mut self, key: &CStr, ty: u32, value: *mut c_void, size: usize) {
@@ -138,45 +242,22 @@ impl<'a> BinnObject {
                 &mut psize as *mut c_int,
             );
 
-            match ptype as u32 {
-                binn_sys::BINN_INT8 => (pval as *const i8).as_ref().map(|p| BinnValue::Int8(*p)),
-                binn_sys::BINN_INT16 => (pval as *const i16).as_ref().map(|p| BinnValue::Int16(*p)),
-                binn_sys::BINN_INT32 => (pval as *const i32).as_ref().map(|p| BinnValue::Int32(*p)),
-                binn_sys::BINN_INT64 => (pval as *const i64).as_ref().map(|p| BinnValue::Int64(*p)),
-                binn_sys::BINN_UINT8 => (pval as *const u8).as_ref().map(|p| BinnValue::UInt8(*p)),
-                binn_sys::BINN_UINT16 => {
-                    (pval as *const u16).as_ref().map(|p| BinnValue::UInt16(*p))
-                }
-                binn_sys::BINN_UINT32 => {
-                    (pval as *const u32).as_ref().map(|p| BinnValue::UInt32(*p))
-                }
-                binn_sys::BINN_UINT64 => {
-                    (pval as *const u64).as_ref().map(|p| BinnValue::UInt64(*p))
-                }
-                binn_sys::BINN_FLOAT32 => (pval as *const f32)
-                    .as_ref()
-                    .map(|p| BinnValue::Float32(*p)),
-                binn_sys::BINN_FLOAT64 => (pval as *const f64)
-                    .as_ref()
-                    .map(|p| BinnValue::Float64(*p)),
-                binn_sys::BINN_BOOL => (pval as *const bool).as_ref().map(|p| BinnValue::Bool(*p)),
-                binn_sys::BINN_STRING => (pval as *const c_char)
-                    .as_ref()
-                    .map(|p| BinnValue::Str(CStr::from_ptr(p))),
-                binn_sys::BINN_OBJECT => {
-                    let bytes = std::slice::from_raw_parts(pval as *const u8, psize as usize);
-                    TryInto::<BinnObject>::try_into(bytes)
-                        .ok()
-                        .map(BinnValue::Object)
-                }
-                _ => None,
-            }
+            raw::value_from_raw(ptype as u32, pval, psize)
         }
     }
 
     pub fn get_as<T: TryFrom<BinnValue<'a>>>(&'a self, key: &CStr) -> Option<T> {
         self.get(key).and_then(|v| v.try_into().ok())
     }
+
+    /// Like [`BinnObject::get_as`], but for numeric types this coerces
+    /// across the stored width/signedness instead of requiring an exact
+    /// match (e.g. a value written as `i32` can be read back as `i64`).
+    /// Narrowing conversions that don't fit the target still fail.
+    pub fn get_coerced<T: CoerceFromBinn>(&self, key: &CStr) -> Option<T> {
+        self.get(key).and_then(T::coerce_from_binn)
+    }
+
     pub fn as_bytes(&self) -> &[u8] {
         unsafe {
             let ptr = binn_sys::binn_ptr(self.0 as *mut c_void);
@@ -184,6 +265,294 @@ impl<'a> BinnObject {
             std::slice::from_raw_parts(ptr as *const u8, size)
         }
     }
+
+    /// Lists every key in this object, in storage order.
+    ///
+    /// `get`/`get_as` are the right tool when the caller already knows which
+    /// keys it wants (e.g. deserializing a struct with a fixed field list);
+    /// this is for callers that need to walk the whole object without
+    /// knowing its shape up front, such as [`de`]'s `HashMap` support.
+    pub(crate) fn keys(&self) -> Vec<CString> {
+        unsafe {
+            let mut iter: binn_sys::binn_iter = std::mem::zeroed();
+            let ptr = binn_ptr(self.0 as *mut c_void);
+            binn_sys::binn_iter_init(&mut iter, ptr as *mut binn_sys::binn, binn_sys::BINN_OBJECT as i32);
+
+            let mut out = Vec::new();
+            loop {
+                let mut key_buf = [0u8; 256];
+                let mut ptype: c_int = 0;
+                let mut psize: c_int = 0;
+                let pval = binn_sys::binn_object_next(
+                    &mut iter,
+                    key_buf.as_mut_ptr() as *mut c_char,
+                    &mut ptype,
+                    &mut psize,
+                );
+                if pval.is_null() {
+                    break;
+                }
+                out.push(CStr::from_ptr(key_buf.as_ptr() as *const c_char).to_owned());
+            }
+            out
+        }
+    }
+
+    /// Encodes [`BinnObject::as_bytes`] as standard-alphabet base64, so the
+    /// buffer can travel through channels (JSON, query strings, ...) that
+    /// reject raw bytes.
+    pub fn to_base64(&self) -> String {
+        base64::encode(self.as_bytes())
+    }
+
+    /// The inverse of [`BinnObject::to_base64`]. Accepts input with or
+    /// without `=` padding.
+    pub fn from_base64(s: &str) -> Result<Self, BinnBase64Error> {
+        let bytes = base64::decode(s).map_err(|_| BinnBase64Error::Decode)?;
+        // Validate before committing to `bytes`: `try_from` opens its own
+        // transient handle rather than keeping `bytes` alive, so if we
+        // returned that handle directly it would dangle the moment this
+        // function returns. `from_owned_bytes` is the constructor that
+        // keeps `bytes` alongside the handle, the way `Clone` does.
+        Self::try_from(bytes.as_slice()).map_err(|_| BinnBase64Error::Open)?;
+        Ok(Self::from_owned_bytes(bytes))
+    }
+
+    /// Renders this object as JSON text.
+    ///
+    /// Objects and [`BinnList`]s become JSON objects/arrays, `Blob` becomes a
+    /// base64 string tagged with a marker prefix so [`BinnObject::from_json`]
+    /// can read it back as a blob rather than a string, and [`BinnMap`]
+    /// becomes a JSON object keyed by its stringified ids. `Int64`/`UInt64`
+    /// values whose magnitude exceeds `2^53` are quoted, since JSON numbers
+    /// are doubles and would otherwise lose precision in clients that parse
+    /// them as floats. `NaN`/`Infinity`/`-Infinity` floats are also quoted,
+    /// with a marker prefix, since JSON numbers have no token for them.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        binn_object_to_json(self, &mut out);
+        out
+    }
+
+    /// The inverse of [`BinnObject::to_json`]. The root value must be a JSON
+    /// object. A quoted string is read back as an `Int64`/`UInt64` only when
+    /// its magnitude exceeds `2^53` — the same threshold `to_json` uses to
+    /// decide whether to quote a number — so this only reinterprets exactly
+    /// the values `to_json` quoted; an ordinary string field that happens to
+    /// look like a small integer (e.g. `"42"`) still comes back as a string.
+    /// Likewise, a string is only read back as a `Blob` when it carries
+    /// `to_json`'s base64 marker prefix, and only read back as a non-finite
+    /// float when it carries `to_json`'s non-finite marker prefix.
+    pub fn from_json(s: &str) -> Result<Self, BinnJsonError> {
+        match json::parse(s).map_err(|_| BinnJsonError::Parse)? {
+            json::Value::Object(entries) => json_object_to_binn(&entries),
+            _ => Err(BinnJsonError::NotAnObject),
+        }
+    }
+}
+
+/// Appends `x` as a JSON number, or — since `NaN`/`inf`/`-inf` have no valid
+/// JSON token and `Display` renders them as `NaN`/`inf`/`-inf` regardless —
+/// as a string tagged with [`NONFINITE_MARKER`] so [`BinnObject::from_json`]
+/// can read it back as the same non-finite float instead of a plain string.
+fn push_float(out: &mut String, x: f64) {
+    if x.is_finite() {
+        let _ = write!(out, "{x}");
+    } else {
+        json::push_escaped_str(out, &format!("{NONFINITE_MARKER}{x}"));
+    }
+}
+
+fn binn_value_to_json(value: BinnValue, out: &mut String) {
+    match value {
+        BinnValue::Int8(x) => json::push_int(out, x as i128),
+        BinnValue::Int16(x) => json::push_int(out, x as i128),
+        BinnValue::Int32(x) => json::push_int(out, x as i128),
+        BinnValue::Int64(x) => json::push_int(out, x as i128),
+        BinnValue::UInt8(x) => json::push_int(out, x as i128),
+        BinnValue::UInt16(x) => json::push_int(out, x as i128),
+        BinnValue::UInt32(x) => json::push_int(out, x as i128),
+        BinnValue::UInt64(x) => json::push_int(out, x as i128),
+        BinnValue::Float32(x) => push_float(out, x as f64),
+        BinnValue::Float64(x) => push_float(out, x),
+        BinnValue::Bool(x) => out.push_str(if x { "true" } else { "false" }),
+        BinnValue::Str(x) => json::push_escaped_str(out, &x.to_string_lossy()),
+        BinnValue::Blob(x) => {
+            json::push_escaped_str(out, &format!("{BLOB_MARKER}{}", base64::encode(x)))
+        }
+        BinnValue::Object(x) => binn_object_to_json(&x, out),
+        BinnValue::List(x) => binn_list_to_json(&x, out),
+        BinnValue::Map(x) => binn_map_to_json(&x, out),
+    }
+}
+
+fn binn_object_to_json(obj: &BinnObject, out: &mut String) {
+    out.push('{');
+    for (i, key) in obj.keys().into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        json::push_escaped_str(out, &key.to_string_lossy());
+        out.push(':');
+        match obj.get(&key) {
+            Some(value) => binn_value_to_json(value, out),
+            None => out.push_str("null"),
+        }
+    }
+    out.push('}');
+}
+
+fn binn_list_to_json(list: &BinnList, out: &mut String) {
+    out.push('[');
+    for pos in 1..=list.len() {
+        if pos > 1 {
+            out.push(',');
+        }
+        match list.get(pos) {
+            Some(value) => binn_value_to_json(value, out),
+            None => out.push_str("null"),
+        }
+    }
+    out.push(']');
+}
+
+fn binn_map_to_json(map: &BinnMap, out: &mut String) {
+    out.push('{');
+    for (i, id) in map.ids().into_iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        json::push_escaped_str(out, &id.to_string());
+        out.push(':');
+        match map.get(id) {
+            Some(value) => binn_value_to_json(value, out),
+            None => out.push_str("null"),
+        }
+    }
+    out.push('}');
+}
+
+fn json_object_to_binn(entries: &[(String, json::Value)]) -> Result<BinnObject, BinnJsonError> {
+    let mut obj = BinnObject::new();
+    for (key, value) in entries {
+        let key = CString::new(key.as_str()).map_err(|_| BinnJsonError::Parse)?;
+        set_json_field(&mut obj, &key, value)?;
+    }
+    Ok(obj)
+}
+
+fn set_json_field(obj: &mut BinnObject, key: &CStr, value: &json::Value) -> Result<(), BinnJsonError> {
+    match value {
+        json::Value::Null => {}
+        json::Value::Bool(x) => obj.set(key, *x),
+        json::Value::Int(x) => set_json_int(obj, key, *x)?,
+        json::Value::Float(x) => obj.set(key, *x),
+        json::Value::Str(s) => match quoted_nonfinite_float(s) {
+            Some(f) => obj.set(key, f),
+            None => match quoted_big_int(s) {
+                Some(n) => set_json_int(obj, key, n)?,
+                None => match quoted_blob(s) {
+                    Some(b) => obj.set(key, b.as_slice()),
+                    None => {
+                        let s = CString::new(s.as_str()).map_err(|_| BinnJsonError::Parse)?;
+                        obj.set(key, s.as_c_str());
+                    }
+                },
+            },
+        },
+        json::Value::Array(items) => obj.set(key, json_array_to_binn(items)?),
+        json::Value::Object(entries) => obj.set(key, json_object_to_binn(entries)?),
+    }
+    Ok(())
+}
+
+fn json_array_to_binn(items: &[json::Value]) -> Result<BinnList, BinnJsonError> {
+    let mut list = BinnList::new();
+    for item in items {
+        add_json_item(&mut list, item)?;
+    }
+    Ok(list)
+}
+
+fn add_json_item(list: &mut BinnList, value: &json::Value) -> Result<(), BinnJsonError> {
+    match value {
+        // A binn list has no "empty slot" tag to round-trip a JSON `null`
+        // through, same limitation `ser`/`de` document for `Option` in
+        // sequences.
+        json::Value::Null => return Err(BinnJsonError::Shape),
+        json::Value::Bool(x) => list.add(*x),
+        json::Value::Int(x) => add_json_int(list, *x)?,
+        json::Value::Float(x) => list.add(*x),
+        json::Value::Str(s) => match quoted_nonfinite_float(s) {
+            Some(f) => list.add(f),
+            None => match quoted_big_int(s) {
+                Some(n) => add_json_int(list, n)?,
+                None => match quoted_blob(s) {
+                    Some(b) => list.add(b.as_slice()),
+                    None => {
+                        let s = CString::new(s.as_str()).map_err(|_| BinnJsonError::Parse)?;
+                        list.add(s.as_c_str());
+                    }
+                },
+            },
+        },
+        json::Value::Array(items) => list.add(json_array_to_binn(items)?),
+        json::Value::Object(entries) => list.add(json_object_to_binn(entries)?),
+    }
+    Ok(())
+}
+
+/// `to_json` only ever quotes an integer once its magnitude exceeds
+/// [`json::MAX_SAFE_INT`] (small ints are always written bare), so that's
+/// the only case in which a quoted string should be read back as a number.
+/// Anything else — including an ordinary string that merely looks numeric,
+/// like `"042"`, or a big number in a form `to_json` never writes (a leading
+/// `+` or leading zeros) — stays a string.
+fn quoted_big_int(s: &str) -> Option<i128> {
+    json::parse_decimal_str(s)
+        .filter(|n| *n > json::MAX_SAFE_INT || *n < -json::MAX_SAFE_INT)
+        .filter(|n| n.to_string() == s)
+}
+
+/// `to_json` tags a `Blob`'s base64 with this prefix so `from_json` can tell
+/// it apart from an ordinary string, the same way a quoted big int is told
+/// apart from a numeric-looking string: by a marker that's only ever
+/// produced on the way out. A literal string that happens to start with
+/// this exact prefix would be misread as a blob; that's an accepted
+/// ambiguity, same as `quoted_big_int`'s.
+const BLOB_MARKER: &str = "\u{0}binn-blob;base64,";
+
+fn quoted_blob(s: &str) -> Option<Vec<u8>> {
+    base64::decode(s.strip_prefix(BLOB_MARKER)?).ok()
+}
+
+/// `push_float` tags a non-finite float's `Display` output with this prefix
+/// so `from_json` can tell it apart from an ordinary string, the same way
+/// [`BLOB_MARKER`] is told apart. Rust's `f64`/`f32` `FromStr` accepts
+/// `Display`'s `"NaN"`/`"inf"`/`"-inf"` right back, so no further formatting
+/// is needed on the way in.
+const NONFINITE_MARKER: &str = "\u{0}binn-float;";
+
+fn quoted_nonfinite_float(s: &str) -> Option<f64> {
+    s.strip_prefix(NONFINITE_MARKER)?.parse::<f64>().ok()
+}
+
+fn set_json_int(obj: &mut BinnObject, key: &CStr, x: i128) -> Result<(), BinnJsonError> {
+    if let Ok(v) = i64::try_from(x) {
+        obj.set(key, v);
+    } else {
+        obj.set(key, u64::try_from(x).map_err(|_| BinnJsonError::Shape)?);
+    }
+    Ok(())
+}
+
+fn add_json_int(list: &mut BinnList, x: i128) -> Result<(), BinnJsonError> {
+    if let Ok(v) = i64::try_from(x) {
+        list.add(v);
+    } else {
+        list.add(u64::try_from(x).map_err(|_| BinnJsonError::Shape)?);
+    }
+    Ok(())
 }
 
 impl Drop for BinnObject {
@@ -198,9 +567,34 @@ impl Default for BinnObject {
     }
 }
 
+impl Clone for BinnObject {
+    fn clone(&self) -> Self {
+        Self::from_owned_bytes(self.as_bytes().to_vec())
+    }
+}
+
 #[derive(Debug)]
 pub struct BinnOpenError;
 
+#[derive(Debug)]
+pub enum BinnBase64Error {
+    /// The input wasn't valid base64.
+    Decode,
+    /// The decoded bytes weren't a valid binn buffer.
+    Open,
+}
+
+#[derive(Debug)]
+pub enum BinnJsonError {
+    /// The input wasn't valid JSON.
+    Parse,
+    /// The JSON was valid but its root value wasn't an object.
+    NotAnObject,
+    /// A value couldn't be represented in binn (e.g. a `null` inside an
+    /// array, or an integer too large for `i64`/`u64`).
+    Shape,
+}
+
 impl TryFrom<&[u8]> for BinnObject {
     type Error = BinnOpenError;
 
@@ -209,7 +603,7 @@ impl TryFrom<&[u8]> for BinnObject {
         if binn.is_null() {
             Err(BinnOpenError)
         } else {
-            Ok(BinnObject(binn))
+            Ok(BinnObject(binn, None))
         }
     }
 }
@@ -297,6 +691,124 @@ mod tests {
         assert_eq!(other_binn.get_as::<bool>(&k("random")), None);
     }
 
+    #[test]
+    fn blob_base64_test() {
+        let mut binn = BinnObject::new();
+
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+        let payload = b"\x00\x01\xfe\xff binary";
+
+        binn.set(&k("blob"), payload.as_slice());
+
+        assert_eq!(binn.get_as::<&[u8]>(&k("blob")), Some(payload.as_slice()));
+
+        let encoded = binn.to_base64();
+        let decoded = BinnObject::from_base64(&encoded).expect("round-trip through base64");
+
+        assert_eq!(decoded.get_as::<&[u8]>(&k("blob")), Some(payload.as_slice()));
+    }
+
+    #[test]
+    fn get_coerced_test() {
+        let mut binn = BinnObject::new();
+
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+
+        binn.set(&k("i32"), 42i32);
+        binn.set(&k("u32"), 300u32);
+        binn.set(&k("neg"), -1i32);
+        binn.set(&k("f64"), 3.9f64);
+        binn.set(&k("bool"), true);
+
+        // Conversions across width/signedness that fit the target work
+        // where the exact-match `get_as` would return `None`.
+        assert_eq!(binn.get_as::<i64>(&k("i32")), None);
+        assert_eq!(binn.get_coerced::<i64>(&k("i32")), Some(42i64));
+        assert_eq!(binn.get_coerced::<u8>(&k("i32")), Some(42u8));
+
+        // Narrowing that overflows the target fails instead of truncating.
+        assert_eq!(binn.get_coerced::<u8>(&k("u32")), None);
+
+        // Signed-to-unsigned of a negative value fails.
+        assert_eq!(binn.get_coerced::<u32>(&k("neg")), None);
+
+        // Float-to-int truncates toward zero when in range.
+        assert_eq!(binn.get_coerced::<i32>(&k("f64")), Some(3));
+
+        // `bool` maps to 0/1.
+        assert_eq!(binn.get_coerced::<u8>(&k("bool")), Some(1));
+    }
+
+    #[test]
+    fn value_owned_test() {
+        use std::collections::HashSet;
+
+        let mut binn = BinnObject::new();
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+
+        binn.set(&k("a"), 42i32);
+        binn.set(&k("b"), 42i32);
+        binn.set(&k("c"), 7i32);
+
+        let a: BinnValueOwned = binn.get(&k("a")).unwrap().into();
+        let b: BinnValueOwned = binn.get(&k("b")).unwrap().into();
+        let c: BinnValueOwned = binn.get(&k("c")).unwrap().into();
+
+        // Equality and ordering compare by value, independent of `binn`.
+        assert_eq!(a, b);
+        assert!(c < a);
+
+        // Usable as a set/map key, and outlives the source object.
+        drop(binn);
+        let mut set = HashSet::new();
+        set.insert(a);
+        set.insert(b);
+        set.insert(c);
+        assert_eq!(set.len(), 2);
+
+        // Bridges back to a borrowed `BinnValue`.
+        let nine = BinnValueOwned::Int32(9);
+        let borrowed: BinnValue = (&nine).try_into().unwrap();
+        assert!(matches!(borrowed, BinnValue::Int32(9)));
+    }
+
+    #[test]
+    fn value_owned_nested_container_test() {
+        let mut outer = BinnObject::new();
+        let mut inner = BinnObject::new();
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+
+        inner.set(&k("val"), 42i8);
+        outer.set(&k("obj"), inner);
+
+        // `get` hands back a nested `BinnObject` that borrows `outer`'s
+        // buffer; converting it to `BinnValueOwned` must take its own copy
+        // rather than alias that buffer.
+        let owned: BinnValueOwned = outer.get(&k("obj")).unwrap().into();
+        drop(outer);
+
+        match owned {
+            BinnValueOwned::Object(obj) => {
+                assert_eq!(obj.get_as::<i8>(&k("val")), Some(42));
+            }
+            _ => panic!("expected an owned Object"),
+        }
+    }
+
+    #[test]
+    fn clone_test() {
+        let mut binn = BinnObject::new();
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+        binn.set(&k("val"), 42i32);
+
+        let cloned = binn.clone();
+        drop(binn);
+
+        // The clone must own an independent copy of the buffer, not alias
+        // the original's (which has just been freed).
+        assert_eq!(cloned.get_as::<i32>(&k("val")), Some(42));
+    }
+
     #[test]
     fn recursive_object_test() {
         let mut outer = BinnObject::new();
@@ -314,4 +826,103 @@ mod tests {
             Some(42)
         );
     }
+
+    #[test]
+    fn json_round_trip_test() {
+        let mut binn = BinnObject::new();
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+        let hello = CStr::from_bytes_with_nul(b"hello\0").unwrap();
+
+        binn.set(&k("i32"), 42i32);
+        binn.set(&k("big"), 9_007_199_254_740_993i64);
+        binn.set(&k("bool"), true);
+        binn.set(&k("str"), hello);
+        binn.set(&k("blob"), b"\x00\x01\xfe".as_slice());
+
+        let mut list = BinnList::new();
+        list.add(1i32);
+        list.add(2i32);
+        binn.set(&k("list"), list);
+
+        let json = binn.to_json();
+
+        // The big `Int64` is quoted to survive double-precision JSON parsers.
+        assert!(json.contains("\"9007199254740993\""));
+        // Ordinary integers stay bare numbers.
+        assert!(json.contains("\"i32\":42"));
+
+        let decoded = BinnObject::from_json(&json).expect("round-trip through JSON");
+
+        // JSON has no integer widths, so every bare number comes back as an
+        // `Int64`/`UInt64`; `get_coerced` reads it back regardless.
+        assert_eq!(decoded.get_coerced::<i32>(&k("i32")), Some(42));
+        assert_eq!(decoded.get_as::<i64>(&k("big")), Some(9_007_199_254_740_993));
+        assert_eq!(decoded.get_as::<bool>(&k("bool")), Some(true));
+        assert_eq!(decoded.get_as::<&CStr>(&k("str")), Some(hello));
+        assert_eq!(
+            decoded.get_as::<&[u8]>(&k("blob")),
+            Some(b"\x00\x01\xfe".as_slice())
+        );
+        assert_eq!(
+            decoded
+                .get_as::<BinnList>(&k("list"))
+                .and_then(|l| l.get_as::<i64>(2)),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn json_numeric_looking_string_test() {
+        let mut binn = BinnObject::new();
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+        let zero_padded = CStr::from_bytes_with_nul(b"042\0").unwrap();
+
+        binn.set(&k("str"), zero_padded);
+
+        let json = binn.to_json();
+        // `to_json` never quotes a small int, so a genuine string that looks
+        // like one is indistinguishable from one in the JSON text itself...
+        assert!(json.contains("\"str\":\"042\""));
+
+        let decoded = BinnObject::from_json(&json).expect("round-trip through JSON");
+        // ...but it must still come back as a string, not an `Int64`, since
+        // `to_json` would never have quoted a value this small.
+        assert_eq!(decoded.get_as::<&CStr>(&k("str")), Some(zero_padded));
+        assert_eq!(decoded.get_as::<i64>(&k("str")), None);
+    }
+
+    #[test]
+    fn json_quoted_i128_min_does_not_panic_test() {
+        let mut binn = BinnObject::new();
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+        let huge = CStr::from_bytes_with_nul(b"-170141183460469231731687303715884105728\0").unwrap();
+
+        // `i128::MIN` has no positive counterpart in `i128`, so the safe-int
+        // check must compare against `MAX_SAFE_INT`/`-MAX_SAFE_INT` directly
+        // rather than negating the value first (negating `i128::MIN`
+        // overflows and panics). This value is too large to fit `i64`/`u64`
+        // either way, so the result is a `Shape` error, not a panic.
+        binn.set(&k("str"), huge);
+        let json = binn.to_json();
+        assert!(matches!(
+            BinnObject::from_json(&json),
+            Err(BinnJsonError::Shape)
+        ));
+    }
+
+    #[test]
+    fn json_surrogate_pair_escape_test() {
+        let k = |s: &str| -> CString { CString::new(s).unwrap() };
+
+        // 🦀 (U+1F980), escaped as the UTF-16 surrogate pair a tool like
+        // Python's `json.dumps(..., ensure_ascii=True)` would emit for a
+        // non-BMP character, instead of the bare UTF-8 bytes `to_json` itself
+        // would produce.
+        let json = r#"{"crab":"\uD83E\uDD80"}"#;
+        let decoded = BinnObject::from_json(json).expect("round-trip through JSON");
+        assert_eq!(
+            decoded.get_as::<&CStr>(&k("crab")),
+            Some(CString::new("\u{1f980}").unwrap().as_c_str())
+        );
+    }
 }
@@ -0,0 +1,218 @@
+//! An owned counterpart to [`BinnValue`] that doesn't borrow from a
+//! backing binn buffer.
+
+use std::cmp::Ordering;
+use std::convert::{Infallible, TryFrom};
+use std::ffi::CString;
+use std::hash::{Hash, Hasher};
+
+use crate::{BinnList, BinnMap, BinnObject, BinnValue};
+
+/// Wraps a float to give it the total `Eq`/`Ord`/`Hash` that `f32`/`f64`
+/// don't provide on their own (`NaN` doesn't equal itself under IEEE 754).
+/// Ordering matches `f32`/`f64::total_cmp`.
+#[derive(Debug, Clone, Copy)]
+struct OrderedFloat<F>(F);
+
+macro_rules! impl_ordered_float {
+    ($t:ty) => {
+        impl PartialEq for OrderedFloat<$t> {
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other) == Ordering::Equal
+            }
+        }
+
+        impl Eq for OrderedFloat<$t> {}
+
+        impl PartialOrd for OrderedFloat<$t> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for OrderedFloat<$t> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for OrderedFloat<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.to_bits().hash(state);
+            }
+        }
+    };
+}
+
+impl_ordered_float!(f32);
+impl_ordered_float!(f64);
+
+/// An owned, lifetime-independent counterpart to [`BinnValue`].
+///
+/// Where `BinnValue::Str`/`Blob` borrow from the backing binn buffer,
+/// `BinnValueOwned` copies what it needs so it can outlive that buffer, be
+/// cloned, and be used as a `HashMap`/`BTreeMap` key.
+#[derive(Debug, Clone)]
+pub enum BinnValueOwned {
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Float32(f32),
+    Float64(f64),
+    Bool(bool),
+    Str(CString),
+    Blob(Vec<u8>),
+    Object(BinnObject),
+    List(BinnList),
+    Map(BinnMap),
+}
+
+impl BinnValueOwned {
+    /// A stable rank used to order values of different variants against
+    /// each other; matches declaration order above.
+    fn rank(&self) -> u8 {
+        match self {
+            BinnValueOwned::Int8(_) => 0,
+            BinnValueOwned::Int16(_) => 1,
+            BinnValueOwned::Int32(_) => 2,
+            BinnValueOwned::Int64(_) => 3,
+            BinnValueOwned::UInt8(_) => 4,
+            BinnValueOwned::UInt16(_) => 5,
+            BinnValueOwned::UInt32(_) => 6,
+            BinnValueOwned::UInt64(_) => 7,
+            BinnValueOwned::Float32(_) => 8,
+            BinnValueOwned::Float64(_) => 9,
+            BinnValueOwned::Bool(_) => 10,
+            BinnValueOwned::Str(_) => 11,
+            BinnValueOwned::Blob(_) => 12,
+            BinnValueOwned::Object(_) => 13,
+            BinnValueOwned::List(_) => 14,
+            BinnValueOwned::Map(_) => 15,
+        }
+    }
+}
+
+impl PartialEq for BinnValueOwned {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BinnValueOwned {}
+
+impl PartialOrd for BinnValueOwned {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BinnValueOwned {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use BinnValueOwned::*;
+        match (self, other) {
+            (Int8(a), Int8(b)) => a.cmp(b),
+            (Int16(a), Int16(b)) => a.cmp(b),
+            (Int32(a), Int32(b)) => a.cmp(b),
+            (Int64(a), Int64(b)) => a.cmp(b),
+            (UInt8(a), UInt8(b)) => a.cmp(b),
+            (UInt16(a), UInt16(b)) => a.cmp(b),
+            (UInt32(a), UInt32(b)) => a.cmp(b),
+            (UInt64(a), UInt64(b)) => a.cmp(b),
+            (Float32(a), Float32(b)) => OrderedFloat(*a).cmp(&OrderedFloat(*b)),
+            (Float64(a), Float64(b)) => OrderedFloat(*a).cmp(&OrderedFloat(*b)),
+            (Bool(a), Bool(b)) => a.cmp(b),
+            (Str(a), Str(b)) => a.cmp(b),
+            (Blob(a), Blob(b)) => a.cmp(b),
+            (Object(a), Object(b)) => a.as_bytes().cmp(b.as_bytes()),
+            (List(a), List(b)) => a.as_bytes().cmp(b.as_bytes()),
+            (Map(a), Map(b)) => a.as_bytes().cmp(b.as_bytes()),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl Hash for BinnValueOwned {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match self {
+            BinnValueOwned::Int8(x) => x.hash(state),
+            BinnValueOwned::Int16(x) => x.hash(state),
+            BinnValueOwned::Int32(x) => x.hash(state),
+            BinnValueOwned::Int64(x) => x.hash(state),
+            BinnValueOwned::UInt8(x) => x.hash(state),
+            BinnValueOwned::UInt16(x) => x.hash(state),
+            BinnValueOwned::UInt32(x) => x.hash(state),
+            BinnValueOwned::UInt64(x) => x.hash(state),
+            BinnValueOwned::Float32(x) => OrderedFloat(*x).hash(state),
+            BinnValueOwned::Float64(x) => OrderedFloat(*x).hash(state),
+            BinnValueOwned::Bool(x) => x.hash(state),
+            BinnValueOwned::Str(x) => x.hash(state),
+            BinnValueOwned::Blob(x) => x.hash(state),
+            BinnValueOwned::Object(x) => x.as_bytes().hash(state),
+            BinnValueOwned::List(x) => x.as_bytes().hash(state),
+            BinnValueOwned::Map(x) => x.as_bytes().hash(state),
+        }
+    }
+}
+
+impl<'a> From<BinnValue<'a>> for BinnValueOwned {
+    fn from(value: BinnValue<'a>) -> Self {
+        match value {
+            BinnValue::Int8(x) => BinnValueOwned::Int8(x),
+            BinnValue::Int16(x) => BinnValueOwned::Int16(x),
+            BinnValue::Int32(x) => BinnValueOwned::Int32(x),
+            BinnValue::Int64(x) => BinnValueOwned::Int64(x),
+            BinnValue::UInt8(x) => BinnValueOwned::UInt8(x),
+            BinnValue::UInt16(x) => BinnValueOwned::UInt16(x),
+            BinnValue::UInt32(x) => BinnValueOwned::UInt32(x),
+            BinnValue::UInt64(x) => BinnValueOwned::UInt64(x),
+            BinnValue::Float32(x) => BinnValueOwned::Float32(x),
+            BinnValue::Float64(x) => BinnValueOwned::Float64(x),
+            BinnValue::Bool(x) => BinnValueOwned::Bool(x),
+            BinnValue::Str(x) => BinnValueOwned::Str(x.to_owned()),
+            BinnValue::Blob(x) => BinnValueOwned::Blob(x.to_vec()),
+            // `BinnObject::get`/`BinnList::get`/`BinnMap::get` hand back
+            // containers that borrow their parent's buffer; `clone()` here
+            // gives this owned value its own independent copy, so it
+            // genuinely outlives that parent rather than aliasing it.
+            BinnValue::Object(x) => BinnValueOwned::Object(x.clone()),
+            BinnValue::List(x) => BinnValueOwned::List(x.clone()),
+            BinnValue::Map(x) => BinnValueOwned::Map(x.clone()),
+        }
+    }
+}
+
+/// The inverse of `From<BinnValue>`, borrowing back out of the owned value.
+/// This never actually fails today (every owned variant has a borrowed
+/// counterpart) but goes through `TryFrom`/`Infallible` to match the rest of
+/// the crate's `BinnValue` conversions, which the `impl_tryfrom!`-generated
+/// impls make fallible on an exact-variant mismatch.
+impl<'a> TryFrom<&'a BinnValueOwned> for BinnValue<'a> {
+    type Error = Infallible;
+
+    fn try_from(value: &'a BinnValueOwned) -> Result<Self, Self::Error> {
+        Ok(match value {
+            BinnValueOwned::Int8(x) => BinnValue::Int8(*x),
+            BinnValueOwned::Int16(x) => BinnValue::Int16(*x),
+            BinnValueOwned::Int32(x) => BinnValue::Int32(*x),
+            BinnValueOwned::Int64(x) => BinnValue::Int64(*x),
+            BinnValueOwned::UInt8(x) => BinnValue::UInt8(*x),
+            BinnValueOwned::UInt16(x) => BinnValue::UInt16(*x),
+            BinnValueOwned::UInt32(x) => BinnValue::UInt32(*x),
+            BinnValueOwned::UInt64(x) => BinnValue::UInt64(*x),
+            BinnValueOwned::Float32(x) => BinnValue::Float32(*x),
+            BinnValueOwned::Float64(x) => BinnValue::Float64(*x),
+            BinnValueOwned::Bool(x) => BinnValue::Bool(*x),
+            BinnValueOwned::Str(x) => BinnValue::Str(x.as_c_str()),
+            BinnValueOwned::Blob(x) => BinnValue::Blob(x.as_slice()),
+            BinnValueOwned::Object(x) => BinnValue::Object(x.clone()),
+            BinnValueOwned::List(x) => BinnValue::List(x.clone()),
+            BinnValueOwned::Map(x) => BinnValue::Map(x.clone()),
+        })
+    }
+}
@@ -0,0 +1,129 @@
+//! Shared encode/decode steps for `binn_object_set`/`binn_list_add`/
+//! `binn_map_set` and their `_read` counterparts.
+//!
+//! All three containers read back a `(ptype, pval, psize)` triple from the
+//! same `BINN_*` type-tag space and need to turn it into a [`crate::BinnValue`]
+//! the same way, and conversely need to turn a `BinnValue` into the
+//! `(ty, ptr, size)` triple their `_set`/`_add` functions expect the same
+//! way; this is those two dispatches, instead of three copies each drifting
+//! apart.
+
+use std::{
+    convert::TryInto,
+    ffi::{c_void, CStr},
+    os::raw::{c_char, c_int},
+};
+
+use crate::{BinnList, BinnMap, BinnObject, BinnOpenError, BinnValue};
+
+/// Dispatches a `BinnValue` to the `(type, pointer, size)` triple the
+/// `binn_object_set`/`binn_list_add`/`binn_map_set` family expects, then
+/// immediately hands it to `sink`. This can't just return the triple:
+/// scalar variants address a local (`x`) that lives only on this call's
+/// stack, and `binn_*_set`/`binn_*_add` read through the pointer
+/// synchronously, so the pointer only needs to survive for the duration of
+/// `sink`, not beyond it.
+pub(crate) fn value_to_raw<R>(value: BinnValue, sink: impl FnOnce(u32, *mut c_void, usize) -> R) -> R {
+    fn addr<T>(x: &T) -> *mut c_void {
+        x as *const T as *mut c_void
+    }
+    match value {
+        BinnValue::Int8(x) => sink(binn_sys::BINN_INT8, addr(&x), 0),
+        BinnValue::Int16(x) => sink(binn_sys::BINN_INT16, addr(&x), 0),
+        BinnValue::Int32(x) => sink(binn_sys::BINN_INT32, addr(&x), 0),
+        BinnValue::Int64(x) => sink(binn_sys::BINN_INT64, addr(&x), 0),
+        BinnValue::UInt8(x) => sink(binn_sys::BINN_UINT8, addr(&x), 0),
+        BinnValue::UInt16(x) => sink(binn_sys::BINN_UINT16, addr(&x), 0),
+        BinnValue::UInt32(x) => sink(binn_sys::BINN_UINT32, addr(&x), 0),
+        BinnValue::UInt64(x) => sink(binn_sys::BINN_UINT64, addr(&x), 0),
+        BinnValue::Float32(x) => sink(binn_sys::BINN_FLOAT32, addr(&x), 0),
+        BinnValue::Float64(x) => sink(binn_sys::BINN_FLOAT64, addr(&x), 0),
+        BinnValue::Bool(x) => sink(binn_sys::BINN_BOOL, addr(&x), 0),
+        BinnValue::Str(x) => sink(binn_sys::BINN_STRING, x.as_ptr() as *mut c_void, 0),
+        BinnValue::Blob(x) => sink(binn_sys::BINN_BLOB, x.as_ptr() as *mut c_void, x.len()),
+        BinnValue::Object(x) => {
+            let bytes = x.as_bytes();
+            sink(binn_sys::BINN_OBJECT, bytes.as_ptr() as *mut c_void, bytes.len())
+        }
+        BinnValue::List(x) => {
+            let bytes = x.as_bytes();
+            sink(binn_sys::BINN_LIST, bytes.as_ptr() as *mut c_void, bytes.len())
+        }
+        BinnValue::Map(x) => {
+            let bytes = x.as_bytes();
+            sink(binn_sys::BINN_MAP, bytes.as_ptr() as *mut c_void, bytes.len())
+        }
+    }
+}
+
+/// # Safety
+/// `pval` and `psize` must be the type/value/size triple `binn_object_read`,
+/// `binn_list_read`, or `binn_map_read` wrote for `ptype`, and the returned
+/// value must not outlive the container `pval` points into.
+pub(crate) unsafe fn value_from_raw<'a>(
+    ptype: u32,
+    pval: *mut c_void,
+    psize: c_int,
+) -> Option<BinnValue<'a>> {
+    match ptype {
+        binn_sys::BINN_INT8 => (pval as *const i8).as_ref().map(|p| BinnValue::Int8(*p)),
+        binn_sys::BINN_INT16 => (pval as *const i16).as_ref().map(|p| BinnValue::Int16(*p)),
+        binn_sys::BINN_INT32 => (pval as *const i32).as_ref().map(|p| BinnValue::Int32(*p)),
+        binn_sys::BINN_INT64 => (pval as *const i64).as_ref().map(|p| BinnValue::Int64(*p)),
+        binn_sys::BINN_UINT8 => (pval as *const u8).as_ref().map(|p| BinnValue::UInt8(*p)),
+        binn_sys::BINN_UINT16 => (pval as *const u16).as_ref().map(|p| BinnValue::UInt16(*p)),
+        binn_sys::BINN_UINT32 => (pval as *const u32).as_ref().map(|p| BinnValue::UInt32(*p)),
+        binn_sys::BINN_UINT64 => (pval as *const u64).as_ref().map(|p| BinnValue::UInt64(*p)),
+        binn_sys::BINN_FLOAT32 => (pval as *const f32).as_ref().map(|p| BinnValue::Float32(*p)),
+        binn_sys::BINN_FLOAT64 => (pval as *const f64).as_ref().map(|p| BinnValue::Float64(*p)),
+        // Not `(pval as *const bool).as_ref()`: `bool` is only valid for the
+        // bit patterns 0/1, so reinterpreting an arbitrary byte as `&bool` is
+        // UB if the writer ever stored anything else there. Read it as a
+        // `u8` and compare instead.
+        binn_sys::BINN_BOOL => (pval as *const u8).as_ref().map(|p| BinnValue::Bool(*p != 0)),
+        binn_sys::BINN_STRING => (pval as *const c_char)
+            .as_ref()
+            .map(|p| BinnValue::Str(CStr::from_ptr(p))),
+        binn_sys::BINN_BLOB => {
+            if pval.is_null() {
+                None
+            } else {
+                let bytes = std::slice::from_raw_parts(pval as *const u8, psize as usize);
+                Some(BinnValue::Blob(bytes))
+            }
+        }
+        binn_sys::BINN_OBJECT => {
+            let bytes = std::slice::from_raw_parts(pval as *const u8, psize as usize);
+            TryInto::<BinnObject>::try_into(bytes).ok().map(BinnValue::Object)
+        }
+        binn_sys::BINN_LIST => {
+            let bytes = std::slice::from_raw_parts(pval as *const u8, psize as usize);
+            TryInto::<BinnList>::try_into(bytes).ok().map(BinnValue::List)
+        }
+        binn_sys::BINN_MAP => {
+            let bytes = std::slice::from_raw_parts(pval as *const u8, psize as usize);
+            TryInto::<BinnMap>::try_into(bytes).ok().map(BinnValue::Map)
+        }
+        _ => None,
+    }
+}
+
+/// Opens `data`'s root container as whichever binn type its header actually
+/// declares (object, list or map), instead of assuming one. Unlike
+/// `value_from_raw`, which is handed a `ptype` that `binn_object_read`/
+/// `binn_list_read`/`binn_map_read` already read for it, there's no such tag
+/// for the root of a buffer — it has to be read straight off the opened
+/// handle, the same way `BinnList::len`/`BinnMap::len` read `count` off it.
+pub(crate) fn open_root(data: &[u8]) -> Result<BinnValue, BinnOpenError> {
+    unsafe {
+        let ptr = binn_sys::binn_open(data.as_ptr() as *mut c_void);
+        if ptr.is_null() {
+            return Err(BinnOpenError);
+        }
+        Ok(match (*ptr).type_ as u32 {
+            binn_sys::BINN_LIST => BinnValue::List(BinnList::from_open_ptr(ptr)),
+            binn_sys::BINN_MAP => BinnValue::Map(BinnMap::from_open_ptr(ptr)),
+            _ => BinnValue::Object(BinnObject::from_open_ptr(ptr)),
+        })
+    }
+}
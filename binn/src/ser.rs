@@ -0,0 +1,426 @@
+//! A `serde::Serializer` that writes directly into a binn buffer.
+
+use std::ffi::{CStr, CString};
+
+use serde::{ser, Serialize};
+
+use crate::error::{Error, Result};
+use crate::{BinnList, BinnObject};
+
+/// Serializes `value` into a fresh binn buffer and returns its bytes.
+pub fn to_bytes<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+    match value.serialize(Serializer)? {
+        Value::Object(obj) => Ok(obj.as_bytes().to_vec()),
+        Value::List(list) => Ok(list.as_bytes().to_vec()),
+        // A unit variant has no container of its own to be the root buffer,
+        // so mark it the same way `serialize_newtype_variant` marks an
+        // elided payload: a single-field object whose key is the variant
+        // name. `de::Deserializer::deserialize_enum`'s `VariantDeserializer`
+        // path reads that key back as the variant tag regardless of what's
+        // stored under it.
+        Value::Variant(variant) => {
+            let mut obj = BinnObject::new();
+            obj.set(&variant, BinnObject::new());
+            Ok(obj.as_bytes().to_vec())
+        }
+        _ => Err(Error::Message(
+            "top-level value must serialize to a struct, map, sequence, tuple or unit enum variant".into(),
+        )),
+    }
+}
+
+/// An in-flight serialized value, before it is folded into its parent
+/// container (or returned as the root buffer).
+pub(crate) enum Value {
+    /// Produced by `None`, `()` and unit structs. Object fields
+    /// holding a `Unit` are simply omitted from the object; `Unit` is not
+    /// valid inside a sequence, since a binn list has no "empty slot" tag.
+    Unit,
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Float32(f32),
+    Float64(f64),
+    Bool(bool),
+    Str(CString),
+    /// Produced only by `serialize_unit_variant`: an enum tag, serialized
+    /// the same as `Str` everywhere except the top level, where `to_bytes`
+    /// gives it a container to live in.
+    Variant(CString),
+    Blob(Vec<u8>),
+    Object(BinnObject),
+    List(BinnList),
+}
+
+fn set_field(obj: &mut BinnObject, key: &CStr, value: Value) -> Result<()> {
+    match value {
+        Value::Unit => {}
+        Value::Int8(x) => obj.set(key, x),
+        Value::Int16(x) => obj.set(key, x),
+        Value::Int32(x) => obj.set(key, x),
+        Value::Int64(x) => obj.set(key, x),
+        Value::UInt8(x) => obj.set(key, x),
+        Value::UInt16(x) => obj.set(key, x),
+        Value::UInt32(x) => obj.set(key, x),
+        Value::UInt64(x) => obj.set(key, x),
+        Value::Float32(x) => obj.set(key, x),
+        Value::Float64(x) => obj.set(key, x),
+        Value::Bool(x) => obj.set(key, x),
+        Value::Str(s) => obj.set(key, s.as_c_str()),
+        Value::Variant(v) => obj.set(key, v.as_c_str()),
+        Value::Blob(b) => obj.set(key, b.as_slice()),
+        Value::Object(o) => obj.set(key, o),
+        Value::List(l) => obj.set(key, l),
+    }
+    Ok(())
+}
+
+fn add_to_list(list: &mut BinnList, value: Value) -> Result<()> {
+    match value {
+        Value::Unit => {
+            return Err(Error::Message(
+                "null/unit values are not supported inside binn sequences".into(),
+            ))
+        }
+        Value::Int8(x) => list.add(x),
+        Value::Int16(x) => list.add(x),
+        Value::Int32(x) => list.add(x),
+        Value::Int64(x) => list.add(x),
+        Value::UInt8(x) => list.add(x),
+        Value::UInt16(x) => list.add(x),
+        Value::UInt32(x) => list.add(x),
+        Value::UInt64(x) => list.add(x),
+        Value::Float32(x) => list.add(x),
+        Value::Float64(x) => list.add(x),
+        Value::Bool(x) => list.add(x),
+        Value::Str(s) => list.add(s.as_c_str()),
+        Value::Variant(v) => list.add(v.as_c_str()),
+        Value::Blob(b) => list.add(b.as_slice()),
+        Value::Object(o) => list.add(o),
+        Value::List(l) => list.add(l),
+    }
+    Ok(())
+}
+
+fn str_key(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|e| Error::Message(e.to_string()))
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct Serializer;
+
+macro_rules! serialize_int {
+    ($method:ident, $t:ty, $variant:ident) => {
+        fn $method(self, v: $t) -> Result<Value> {
+            Ok(Value::$variant(v))
+        }
+    };
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = TupleVariantSerializer;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructVariantSerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<Value> {
+        Ok(Value::Bool(v))
+    }
+
+    serialize_int!(serialize_i8, i8, Int8);
+    serialize_int!(serialize_i16, i16, Int16);
+    serialize_int!(serialize_i32, i32, Int32);
+    serialize_int!(serialize_i64, i64, Int64);
+    serialize_int!(serialize_u8, u8, UInt8);
+    serialize_int!(serialize_u16, u16, UInt16);
+    serialize_int!(serialize_u32, u32, UInt32);
+    serialize_int!(serialize_u64, u64, UInt64);
+    serialize_int!(serialize_f32, f32, Float32);
+    serialize_int!(serialize_f64, f64, Float64);
+
+    fn serialize_char(self, v: char) -> Result<Value> {
+        self.serialize_str(v.encode_utf8(&mut [0u8; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value> {
+        Ok(Value::Str(str_key(v)?))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value> {
+        Ok(Value::Blob(v.to_vec()))
+    }
+
+    fn serialize_none(self) -> Result<Value> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value> {
+        Ok(Value::Unit)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value> {
+        Ok(Value::Variant(str_key(variant)?))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value> {
+        let mut obj = BinnObject::new();
+        let key = str_key(variant)?;
+        match value.serialize(self)? {
+            // Unlike an ordinary struct field, this key can't just be
+            // omitted when the payload is `()`/`None`: it's the only thing
+            // that identifies the variant, so an omitted key here would
+            // leave an empty, undecodable object. Mark it with an empty
+            // nested object instead; `de::VariantDeserializer` recognizes
+            // that marker as an elided payload rather than real data.
+            Value::Unit => obj.set(&key, BinnObject::new()),
+            payload => set_field(&mut obj, &key, payload)?,
+        }
+        Ok(Value::Object(obj))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqSerializer> {
+        Ok(SeqSerializer { list: BinnList::new() })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<TupleVariantSerializer> {
+        Ok(TupleVariantSerializer {
+            variant: str_key(variant)?,
+            seq: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer> {
+        Ok(MapSerializer {
+            obj: BinnObject::new(),
+            pending_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<StructSerializer> {
+        Ok(StructSerializer { obj: BinnObject::new() })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<StructVariantSerializer> {
+        Ok(StructVariantSerializer {
+            variant: str_key(variant)?,
+            inner: self.serialize_struct(_name, len)?,
+        })
+    }
+}
+
+pub(crate) struct SeqSerializer {
+    list: BinnList,
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        add_to_list(&mut self.list, value.serialize(Serializer)?)
+    }
+
+    fn end(self) -> Result<Value> {
+        Ok(Value::List(self.list))
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+pub(crate) struct TupleVariantSerializer {
+    variant: CString,
+    seq: SeqSerializer,
+}
+
+impl ser::SerializeTupleVariant for TupleVariantSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(&mut self.seq, value)
+    }
+
+    fn end(self) -> Result<Value> {
+        let mut obj = BinnObject::new();
+        set_field(&mut obj, &self.variant, ser::SerializeSeq::end(self.seq)?)?;
+        Ok(Value::Object(obj))
+    }
+}
+
+pub(crate) struct MapSerializer {
+    obj: BinnObject,
+    pending_key: Option<CString>,
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        self.pending_key = Some(match key.serialize(Serializer)? {
+            Value::Str(s) => s,
+            Value::Variant(s) => s,
+            Value::Int8(x) => str_key(&x.to_string())?,
+            Value::Int16(x) => str_key(&x.to_string())?,
+            Value::Int32(x) => str_key(&x.to_string())?,
+            Value::Int64(x) => str_key(&x.to_string())?,
+            Value::UInt8(x) => str_key(&x.to_string())?,
+            Value::UInt16(x) => str_key(&x.to_string())?,
+            Value::UInt32(x) => str_key(&x.to_string())?,
+            Value::UInt64(x) => str_key(&x.to_string())?,
+            _ => return Err(Error::Message("map keys must serialize to a string or integer".into())),
+        });
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let key = self
+            .pending_key
+            .take()
+            .expect("serialize_value called before serialize_key");
+        set_field(&mut self.obj, &key, value.serialize(Serializer)?)
+    }
+
+    fn end(self) -> Result<Value> {
+        Ok(Value::Object(self.obj))
+    }
+}
+
+pub(crate) struct StructSerializer {
+    obj: BinnObject,
+}
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        set_field(&mut self.obj, &str_key(key)?, value.serialize(Serializer)?)
+    }
+
+    fn end(self) -> Result<Value> {
+        Ok(Value::Object(self.obj))
+    }
+}
+
+pub(crate) struct StructVariantSerializer {
+    variant: CString,
+    inner: StructSerializer,
+}
+
+impl ser::SerializeStructVariant for StructVariantSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Value> {
+        let mut obj = BinnObject::new();
+        set_field(
+            &mut obj,
+            &self.variant,
+            ser::SerializeStruct::end(self.inner)?,
+        )?;
+        Ok(Value::Object(obj))
+    }
+}